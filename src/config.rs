@@ -0,0 +1,89 @@
+use std::{env, fs};
+
+use serde::Deserialize;
+
+const DEFAULT_TARGET_LANGUAGE: &str = "Japanese";
+const DEFAULT_MODEL: &str = "gpt-3.5-turbo";
+const DEFAULT_MAX_RESULTS: i64 = 10;
+const DEFAULT_PICKS: usize = 3;
+const DEFAULT_PROMPT_TEMPLATE: &str = r#"Translate the following paper's title and summary into {language} and respond in this format:
+
+タイトル:
+(translated title)
+
+概要:
+(translated summary)
+
+title: {title}
+summary: {summary}"#;
+
+/// Translation-related settings, layered as env vars overriding an optional TOML file
+/// overriding built-in defaults.
+#[derive(Debug, Clone)]
+pub struct TranslationConfig {
+    pub target_language: String,
+    pub model: String,
+    pub prompt_template: String,
+    pub max_results: i64,
+    pub picks: usize,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileConfig {
+    target_language: Option<String>,
+    model: Option<String>,
+    prompt_template: Option<String>,
+    max_results: Option<i64>,
+    picks: Option<usize>,
+}
+
+impl TranslationConfig {
+    /// Loads the TOML file at `CONFIG_FILE` (default `config.toml`, skipped if missing),
+    /// then lets `TARGET_LANGUAGE`/`LLM_MODEL`/`PROMPT_TEMPLATE`/`MAX_RESULTS`/`PICKS`
+    /// env vars override whatever it set.
+    pub fn load() -> Result<Self, String> {
+        let file = load_file_config()?;
+
+        Ok(Self {
+            target_language: env::var("TARGET_LANGUAGE")
+                .ok()
+                .or(file.target_language)
+                .unwrap_or_else(|| DEFAULT_TARGET_LANGUAGE.to_string()),
+            model: env::var("LLM_MODEL")
+                .ok()
+                .or(file.model)
+                .unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+            prompt_template: env::var("PROMPT_TEMPLATE")
+                .ok()
+                .or(file.prompt_template)
+                .unwrap_or_else(|| DEFAULT_PROMPT_TEMPLATE.to_string()),
+            max_results: env::var("MAX_RESULTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .or(file.max_results)
+                .unwrap_or(DEFAULT_MAX_RESULTS),
+            picks: env::var("PICKS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .or(file.picks)
+                .unwrap_or(DEFAULT_PICKS),
+        })
+    }
+
+    /// Renders the prompt template for a single paper, substituting the
+    /// `{language}`, `{title}`, and `{summary}` placeholders.
+    pub fn render_prompt(&self, title: &str, summary: &str) -> String {
+        self.prompt_template
+            .replace("{language}", &self.target_language)
+            .replace("{title}", title)
+            .replace("{summary}", summary)
+    }
+}
+
+fn load_file_config() -> Result<FileConfig, String> {
+    let path = env::var("CONFIG_FILE").unwrap_or_else(|_| "config.toml".to_string());
+    match fs::read_to_string(&path) {
+        Ok(raw) => toml::from_str(&raw).map_err(|e| format!("🛑 Failed to parse {}: {}", path, e)),
+        Err(_) => Ok(FileConfig::default()),
+    }
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use arxiv::Arxiv;
+use chrono::Utc;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+
+/// Wraps the SQLite pool tracking which arXiv papers have already been posted to Slack.
+pub struct DedupStore {
+    pool: SqlitePool,
+}
+
+impl DedupStore {
+    /// Connects to `DATABASE_URL` (creating the database file if needed) and runs migrations.
+    pub async fn connect(database_url: &str) -> Result<Self, String> {
+        let options = SqliteConnectOptions::from_str(database_url)
+            .map_err(|e| format!("🛑 Invalid DATABASE_URL {}: {}", database_url, e))?
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect_with(options)
+            .await
+            .map_err(|e| format!("🛑 Failed to connect to {}: {}", database_url, e))?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS posted_papers (
+                arxiv_id TEXT PRIMARY KEY,
+                posted_at TIMESTAMP NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("🛑 Failed to run migrations: {}", e))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Drops any arxivs we've already posted, preserving the input order.
+    pub async fn filter_unposted(&self, arxivs: Vec<Arxiv>) -> Result<Vec<Arxiv>, String> {
+        let mut unposted = Vec::with_capacity(arxivs.len());
+        for arxiv in arxivs {
+            let already_posted = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM posted_papers WHERE arxiv_id = ?",
+            )
+            .bind(&arxiv.id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| format!("🛑 Failed to query posted_papers: {}", e))?
+                > 0;
+            if !already_posted {
+                unposted.push(arxiv);
+            }
+        }
+        Ok(unposted)
+    }
+
+    /// Records an arxiv id as posted. Call this only after a successful Slack post,
+    /// so a failed post is retried on the next run instead of being skipped forever.
+    pub async fn mark_posted(&self, arxiv_id: &str) -> Result<(), String> {
+        sqlx::query("INSERT OR REPLACE INTO posted_papers (arxiv_id, posted_at) VALUES (?, ?)")
+            .bind(arxiv_id)
+            .bind(Utc::now())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("🛑 Failed to record posted paper: {}", e))?;
+        Ok(())
+    }
+
+    /// Prunes rows older than `retention_days` so the table doesn't grow forever.
+    pub async fn prune_older_than(&self, retention_days: i64) -> Result<(), String> {
+        sqlx::query("DELETE FROM posted_papers WHERE datetime(posted_at) < datetime('now', ?)")
+            .bind(format!("-{} days", retention_days))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("🛑 Failed to prune posted_papers: {}", e))?;
+        Ok(())
+    }
+}
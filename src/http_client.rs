@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{header::HeaderMap, RequestBuilder, Response, StatusCode};
+use tokio::time::sleep;
+
+const BASE_DELAY_MS: u64 = 500;
+const MAX_DELAY_MS: u64 = 30_000;
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// A thin wrapper around `reqwest` that retries 429s, 5xxs, and connection errors
+/// with exponential backoff (honoring `Retry-After` when the server sends one).
+pub struct RetryingClient {
+    max_attempts: u32,
+}
+
+impl RetryingClient {
+    /// `max_attempts` is clamped to at least 1 so "don't retry" (`0`) still makes a single
+    /// attempt instead of sending zero requests.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let max_attempts = std::env::var("HTTP_MAX_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ATTEMPTS);
+        Self::new(max_attempts)
+    }
+
+    /// Sends `request`, retrying on 429/5xx/connection errors up to `max_attempts` times.
+    /// The builder must be cloneable (no streaming body), which holds for our JSON requests.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, String> {
+        for attempt in 1..=self.max_attempts {
+            let req = request
+                .try_clone()
+                .ok_or_else(|| "🛑 Request isn't cloneable, can't retry".to_string())?;
+
+            match req.send().await {
+                Ok(response) if should_retry_status(response.status()) => {
+                    if attempt == self.max_attempts {
+                        return Ok(response);
+                    }
+                    let delay =
+                        retry_after_delay(response.headers()).unwrap_or_else(|| backoff_delay(attempt));
+                    sleep(delay).await;
+                }
+                Ok(response) => return Ok(response),
+                Err(e) => {
+                    if attempt == self.max_attempts {
+                        return Err(format!("🛑 Request failed after {} attempts: {}", attempt, e));
+                    }
+                    sleep(backoff_delay(attempt)).await;
+                }
+            }
+        }
+        unreachable!("loop always returns on its last attempt")
+    }
+}
+
+fn should_retry_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn retry_after_delay(headers: &HeaderMap) -> Option<Duration> {
+    let seconds: u64 = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Exponential backoff from `BASE_DELAY_MS`, doubling per attempt, capped at `MAX_DELAY_MS`,
+/// with up to 25% jitter added to avoid synchronized retries.
+fn backoff_delay(attempt: u32) -> Duration {
+    let exponential = BASE_DELAY_MS.saturating_mul(1 << (attempt - 1).min(16));
+    let capped = exponential.min(MAX_DELAY_MS);
+    let jitter = rand::thread_rng().gen_range(0..=capped / 4);
+    Duration::from_millis(capped + jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_with_each_attempt() {
+        let first = backoff_delay(1).as_millis();
+        let second = backoff_delay(2).as_millis();
+        assert!((BASE_DELAY_MS as u128..=(BASE_DELAY_MS as u128) * 5 / 4).contains(&first));
+        assert!(second >= (BASE_DELAY_MS as u128) * 2);
+    }
+
+    #[test]
+    fn backoff_delay_is_capped_at_max_delay_plus_jitter() {
+        let delay = backoff_delay(20).as_millis();
+        assert!(delay <= (MAX_DELAY_MS as u128) * 5 / 4);
+    }
+
+    #[test]
+    fn retry_after_delay_reads_the_header_in_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after_delay(&headers), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn retry_after_delay_is_none_without_the_header() {
+        assert_eq!(retry_after_delay(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn new_clamps_zero_max_attempts_to_one() {
+        assert_eq!(RetryingClient::new(0).max_attempts, 1);
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+pub mod openai;
+pub mod vertex;
+
+/// A backend capable of translating a paper's title/summary into the target language.
+#[async_trait]
+pub trait TranslationBackend {
+    async fn translate(&self, title: &str, summary: &str) -> Result<String, String>;
+}
+
+/// Builds the `TranslationBackend` selected by `LLM_PROVIDER` (defaults to `openai`).
+pub fn backend_from_env() -> Result<Box<dyn TranslationBackend + Send + Sync>, String> {
+    let provider = std::env::var("LLM_PROVIDER").unwrap_or_else(|_| "openai".to_string());
+    match provider.as_str() {
+        "openai" => Ok(Box::new(openai::OpenAiBackend::from_env()?)),
+        "vertex" => Ok(Box::new(vertex::VertexBackend::from_env()?)),
+        other => Err(format!("🛑 Unknown LLM_PROVIDER: {}", other)),
+    }
+}
@@ -0,0 +1,115 @@
+use std::env;
+
+use async_trait::async_trait;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use crate::config::TranslationConfig;
+use crate::http_client::RetryingClient;
+
+use super::TranslationBackend;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Root {
+    pub id: String,
+    pub object: String,
+    pub created: i64,
+    pub model: String,
+    pub usage: Usage,
+    pub choices: Vec<Choice>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Usage {
+    #[serde(rename = "prompt_tokens")]
+    pub prompt_tokens: i64,
+    #[serde(rename = "completion_tokens")]
+    pub completion_tokens: i64,
+    #[serde(rename = "total_tokens")]
+    pub total_tokens: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Choice {
+    pub message: Message,
+    #[serde(rename = "finish_reason")]
+    pub finish_reason: String,
+    pub index: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Body {
+    pub model: String,
+    pub messages: Vec<Message>,
+}
+
+pub struct OpenAiBackend {
+    api_key: String,
+    client: RetryingClient,
+    config: TranslationConfig,
+}
+
+impl OpenAiBackend {
+    pub fn from_env() -> Result<Self, String> {
+        let api_key = env::var("OPENAI_KEY").map_err(|_| "OPENAI_KEY is not set".to_string())?;
+        let config = TranslationConfig::load()?;
+        Ok(Self {
+            api_key,
+            client: RetryingClient::from_env(),
+            config,
+        })
+    }
+}
+
+#[async_trait]
+impl TranslationBackend for OpenAiBackend {
+    async fn translate(&self, title: &str, summary: &str) -> Result<String, String> {
+        let bearer_auth = format!("Bearer {}", self.api_key);
+        let prompt = self.config.render_prompt(title, summary);
+        let data = Body {
+            model: self.config.model.clone(),
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: prompt,
+            }],
+        };
+
+        let url = "https://api.openai.com/v1/chat/completions".to_string();
+        let http_client = reqwest::Client::new();
+        let request = http_client
+            .post(url)
+            .header(ACCEPT, "*/*")
+            .header(AUTHORIZATION, &bearer_auth)
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&data).unwrap());
+        let response = self
+            .client
+            .send(request)
+            .await
+            .map_err(|e| format!("🛑 Request to OpenAI failed: {}", e))?;
+        match response.status() {
+            reqwest::StatusCode::OK => match response.json::<Root>().await {
+                Ok(parsed) => Ok(parsed.choices[0].message.content.to_string()),
+                Err(_) => Err("🛑 Hm, the response didn't match the shape we expected.".to_string()),
+            },
+            reqwest::StatusCode::UNAUTHORIZED => {
+                Err("🛑 Status: UNAUTHORIZED - Need to grab a new token".to_string())
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                Err("🛑 Status: 429 - Too many requests".to_string())
+            }
+            status => Err(format!("🛑 Status: {:?} - Something unexpected happened", status)),
+        }
+    }
+}
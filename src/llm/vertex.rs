@@ -0,0 +1,213 @@
+use std::{env, fs, time::Duration};
+
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::config::TranslationConfig;
+use crate::http_client::RetryingClient;
+
+use super::TranslationBackend;
+
+const TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+/// Refresh a bit before expiry so an in-flight request never races a stale token.
+const REFRESH_SKEW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iss: String,
+    scope: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Serialize)]
+struct VertexRequest {
+    contents: Vec<Content>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Content {
+    role: String,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Part {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VertexResponse {
+    candidates: Vec<Candidate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Candidate {
+    content: Content,
+}
+
+pub struct VertexBackend {
+    project_id: String,
+    location: String,
+    model: String,
+    service_account: ServiceAccountKey,
+    client: RetryingClient,
+    token_cache: Mutex<Option<CachedToken>>,
+    config: TranslationConfig,
+}
+
+impl VertexBackend {
+    pub fn from_env() -> Result<Self, String> {
+        let project_id =
+            env::var("VERTEX_PROJECT_ID").map_err(|_| "VERTEX_PROJECT_ID is not set".to_string())?;
+        let location =
+            env::var("VERTEX_LOCATION").unwrap_or_else(|_| "us-central1".to_string());
+        let credentials_path = env::var("GOOGLE_APPLICATION_CREDENTIALS")
+            .map_err(|_| "GOOGLE_APPLICATION_CREDENTIALS is not set".to_string())?;
+        let raw = fs::read_to_string(&credentials_path)
+            .map_err(|e| format!("🛑 Failed to read {}: {}", credentials_path, e))?;
+        let service_account: ServiceAccountKey =
+            serde_json::from_str(&raw).map_err(|e| format!("🛑 Invalid service account key: {}", e))?;
+        let config = TranslationConfig::load()?;
+        // VERTEX_MODEL takes precedence since Vertex model ids (e.g. gemini-1.5-flash) are
+        // provider-specific; otherwise fall back to the shared LLM_MODEL/config.toml setting
+        // so a TOML-configured model applies to both backends.
+        let model = env::var("VERTEX_MODEL")
+            .ok()
+            .unwrap_or_else(|| config.model.clone());
+
+        Ok(Self {
+            project_id,
+            location,
+            model,
+            service_account,
+            client: RetryingClient::from_env(),
+            token_cache: Mutex::new(None),
+            config,
+        })
+    }
+
+    /// Exchanges the service-account key for a short-lived OAuth2 access token,
+    /// caching it until it's within `REFRESH_SKEW` of expiring.
+    async fn access_token(&self) -> Result<String, String> {
+        let mut cache = self.token_cache.lock().await;
+        if let Some(cached) = cache.as_ref() {
+            if cached.expires_at > Instant::now() + REFRESH_SKEW {
+                return Ok(cached.access_token.clone());
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let claims = Claims {
+            iss: self.service_account.client_email.clone(),
+            scope: SCOPE.to_string(),
+            aud: TOKEN_URI.to_string(),
+            iat: now,
+            exp: now + 3600,
+        };
+        let encoding_key = EncodingKey::from_rsa_pem(self.service_account.private_key.as_bytes())
+            .map_err(|e| format!("🛑 Invalid private key: {}", e))?;
+        let jwt = jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)
+            .map_err(|e| format!("🛑 Failed to sign JWT: {}", e))?;
+
+        let http_client = reqwest::Client::new();
+        let request = http_client.post(TOKEN_URI).form(&[
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", jwt.as_str()),
+        ]);
+        let response = self
+            .client
+            .send(request)
+            .await
+            .map_err(|e| format!("🛑 Request to Google token endpoint failed: {}", e))?;
+
+        if response.status() != reqwest::StatusCode::OK {
+            return Err(format!(
+                "🛑 Status: {} - Failed to obtain Vertex access token",
+                response.status()
+            ));
+        }
+        let token: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("🛑 Unexpected token response shape: {}", e))?;
+
+        let expires_at = Instant::now() + Duration::from_secs(token.expires_in);
+        cache.replace(CachedToken {
+            access_token: token.access_token.clone(),
+            expires_at,
+        });
+        Ok(token.access_token)
+    }
+}
+
+#[async_trait]
+impl TranslationBackend for VertexBackend {
+    async fn translate(&self, title: &str, summary: &str) -> Result<String, String> {
+        let access_token = self.access_token().await?;
+        let prompt = self.config.render_prompt(title, summary);
+        let request = VertexRequest {
+            contents: vec![Content {
+                role: "user".to_string(),
+                parts: vec![Part { text: prompt }],
+            }],
+        };
+
+        let url = format!(
+            "https://{}-aiplatform.googleapis.com/v1/projects/{}/locations/{}/publishers/google/models/{}:generateContent",
+            self.location, self.project_id, self.location, self.model
+        );
+        let http_client = reqwest::Client::new();
+        let http_request = http_client
+            .post(url)
+            .header(AUTHORIZATION, format!("Bearer {}", access_token))
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&request).unwrap());
+        let response = self
+            .client
+            .send(http_request)
+            .await
+            .map_err(|e| format!("🛑 Request to Vertex AI failed: {}", e))?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => match response.json::<VertexResponse>().await {
+                Ok(parsed) => parsed
+                    .candidates
+                    .first()
+                    .and_then(|c| c.content.parts.first())
+                    .map(|p| p.text.clone())
+                    .ok_or_else(|| "🛑 Vertex response had no candidates".to_string()),
+                Err(_) => Err("🛑 Hm, the response didn't match the shape we expected.".to_string()),
+            },
+            reqwest::StatusCode::UNAUTHORIZED => {
+                Err("🛑 Status: UNAUTHORIZED - Need to grab a new token".to_string())
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                Err("🛑 Status: 429 - Too many requests".to_string())
+            }
+            status => Err(format!("🛑 Status: {:?} - Something unexpected happened", status)),
+        }
+    }
+}
@@ -1,193 +1,94 @@
-use std::{cmp::min, env};
+use std::env;
 
 use anyhow::Result;
-use arxiv::Arxiv;
-use rand::seq::SliceRandom;
-use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
-use serde::{Deserialize, Serialize};
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Root {
-    pub id: String,
-    pub object: String,
-    pub created: i64,
-    pub model: String,
-    pub usage: Usage,
-    pub choices: Vec<Choice>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Usage {
-    #[serde(rename = "prompt_tokens")]
-    pub prompt_tokens: i64,
-    #[serde(rename = "completion_tokens")]
-    pub completion_tokens: i64,
-    #[serde(rename = "total_tokens")]
-    pub total_tokens: i64,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Choice {
-    pub message: Message,
-    #[serde(rename = "finish_reason")]
-    pub finish_reason: String,
-    pub index: i64,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Message {
-    pub role: String,
-    pub content: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Body {
-    pub model: String,
-    pub messages: Vec<Message>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SlackMessage {
-    pub channel: String,
-    pub text: String,
-}
+use tokio::sync::mpsc;
+
+mod config;
+mod db;
+mod http_client;
+mod llm;
+mod pipeline;
+mod server;
+mod slack;
+mod worker;
+
+use config::TranslationConfig;
+use db::DedupStore;
+use slack::SlackClient;
+
+/// Number of days of posted-paper history to keep before pruning.
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+/// How many ad-hoc slash-command searches can be queued before the worker catches up.
+const WORKER_QUEUE_SIZE: usize = 32;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // .envファイルを読み込む
     dotenv::dotenv().ok();
-    let search_query = env::var("SEARCH_QUERY").expect("SEARCH_QUERY is not set");
-    let openai_key = env::var("OPENAI_KEY").expect("SEARCH_QUERY is not set");
     let slack_token = env::var("SLACK_TOKEN").expect("SLACK_TOKEN is not set");
-    let slack_channel = env::var("SLACK_CHANNEL").expect("SLACK_CHANNEL is not set");
-
-    // 論文を検索する
-    let query = arxiv::ArxivQueryBuilder::new()
-        .search_query(&search_query)
-        .start(0)
-        .max_results(10)
-        .sort_by("submittedDate")
-        .sort_order("descending")
-        .build();
-    let mut arxivs = arxiv::fetch_arxivs(query).await?;
-
-    // arxivsからランダムに3つ選ぶ
-    arxivs.shuffle(&mut rand::thread_rng());
-    for i in 0..min(3, arxivs.len()) {
-        let message = translate_paper(&arxivs[i], &openai_key).await;
-
-        // slackに投稿する
-        let response = post_to_slack(
-            &SlackMessage {
-                channel: slack_channel.clone(),
-                text: message.unwrap(),
-            },
-            &slack_token,
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set");
+    let retention_days = env::var("RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+    let translation_config = TranslationConfig::load().expect("failed to load translation config");
+    let (max_results, picks) = (translation_config.max_results, translation_config.picks);
+    let backend = llm::backend_from_env().expect("failed to build LLM backend");
+    let store = DedupStore::connect(&database_url)
+        .await
+        .expect("failed to connect to the dedup store");
+    store
+        .prune_older_than(retention_days)
+        .await
+        .expect("failed to prune old posted_papers rows");
+
+    let daemon_mode = env::var("DAEMON_MODE")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+
+    if daemon_mode {
+        run_daemon(backend, store, slack_token, max_results, picks).await
+    } else {
+        let search_query = env::var("SEARCH_QUERY").expect("SEARCH_QUERY is not set");
+        let slack_channel = env::var("SLACK_CHANNEL").expect("SLACK_CHANNEL is not set");
+        let slack = SlackClient::new(slack_token);
+        pipeline::run_search(
+            backend.as_ref(),
+            &store,
+            &slack,
+            &search_query,
+            &slack_channel,
+            max_results,
+            picks,
         )
-        .await;
-
-        match response {
-            Ok(_) => println!("🎉 Successfully posted to Slack"),
-            Err(e) => println!("{}", e),
-        }
-    }
-
-    Ok(())
-}
-
-async fn post_to_slack(message: &SlackMessage, token: &String) -> Result<String, String> {
-    let bearer_auth = format!("Bearer {}", token);
-    let url = "https://slack.com/api/chat.postMessage".to_string();
-
-    let client = reqwest::Client::new();
-    let response = client
-        .post(url)
-        .header(ACCEPT, "*/*")
-        .header(AUTHORIZATION, bearer_auth)
-        .header(CONTENT_TYPE, "application/json")
-        .body(serde_json::to_string(&message).unwrap())
-        .send()
         .await
-        .unwrap();
-    match response.status() {
-        reqwest::StatusCode::OK => {
-            let body = response.text().await.unwrap();
-            Ok(body)
-        }
-        reqwest::StatusCode::UNAUTHORIZED => {
-            Err("🛑 Status: UNAUTHORIZED - Need to grab a new token".to_string())
-        }
-        reqwest::StatusCode::TOO_MANY_REQUESTS => {
-            Err("🛑 Status: 429 - Too many requests".to_string())
-        }
-        _ => Err("🛑 Status: {:#?} - Something unexpected happened".to_string()),
+        .map_err(anyhow::Error::msg)
     }
 }
 
-async fn translate_paper(arxiv: &Arxiv, key: &String) -> Result<String, String> {
-    // TODO:
-    let bearer_auth = format!("Bearer {}", key);
-    let system = r#"与えられた英語の論文を日本語に訳し、以下のフォーマットで出力してください。
-    ```
-    タイトル:
-    タイトルの日本語訳
-
-    概要:
-    概要の日本語訳
-    ```
-    "#;
-    let user = format!("title: {}\nsummary: {}", arxiv.title, arxiv.summary);
-    let data: Body = Body {
-        model: "gpt-3.5-turbo".to_string(),
-        messages: vec![
-            Message {
-                role: "system".to_string(),
-                content: system.to_string(),
-            },
-            Message {
-                role: "user".to_string(),
-                content: user.to_string(),
-            },
-        ],
-    };
-
-    let url = "https://api.openai.com/v1/chat/completions".to_string();
-    let client = reqwest::Client::new();
-    let response = client
-        .post(url)
-        .header(ACCEPT, "*/*")
-        .header(AUTHORIZATION, &bearer_auth)
-        .header(CONTENT_TYPE, "application/json")
-        .body(serde_json::to_string(&data).unwrap())
-        .send()
+/// Runs the bot as a long-lived daemon: a worker task drains ad-hoc searches coming in
+/// from Slack's `/paper` slash command while the HTTP server just acks and enqueues.
+async fn run_daemon(
+    backend: Box<dyn llm::TranslationBackend + Send + Sync>,
+    store: DedupStore,
+    slack_token: String,
+    max_results: i64,
+    picks: usize,
+) -> Result<()> {
+    let signing_secret =
+        env::var("SLACK_SIGNING_SECRET").expect("SLACK_SIGNING_SECRET is not set");
+    let addr = env::var("LISTEN_ADDR")
+        .unwrap_or_else(|_| "0.0.0.0:3000".to_string())
+        .parse()
+        .expect("LISTEN_ADDR must be a socket address");
+
+    let (sender, receiver) = mpsc::channel(WORKER_QUEUE_SIZE);
+    let slack = SlackClient::new(slack_token);
+    let worker_handle = tokio::spawn(worker::run(receiver, backend, store, slack, max_results, picks));
+
+    server::serve(addr, signing_secret, sender)
         .await
-        .unwrap();
-    match response.status() {
-        reqwest::StatusCode::OK => match response.json::<Root>().await {
-            Ok(parsed) => {
-                let response = format!(
-                    "発行日: {}\n{}\n{}\n{}\n",
-                    arxiv.published,
-                    arxiv.pdf_url,
-                    arxiv.title,
-                    parsed.choices[0].message.content.to_string()
-                );
-                Ok(response)
-            }
-            Err(_) => Err("🛑 Hm, the response didn't match the shape we expected.".to_string()),
-        },
-        reqwest::StatusCode::UNAUTHORIZED => {
-            Err("🛑 Status: UNAUTHORIZED - Need to grab a new token".to_string())
-        }
-        reqwest::StatusCode::TOO_MANY_REQUESTS => {
-            Err("🛑 Status: 429 - Too many requests".to_string())
-        }
-        _ => Err("🛑 Status: {:#?} - Something unexpected happened".to_string()),
-    }
+        .map_err(anyhow::Error::msg)?;
+    worker_handle.await.ok();
+    Ok(())
 }
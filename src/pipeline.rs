@@ -0,0 +1,64 @@
+use std::cmp::min;
+
+use rand::seq::SliceRandom;
+
+use crate::db::DedupStore;
+use crate::llm::TranslationBackend;
+use crate::slack::SlackClient;
+
+/// Fetches the newest arXiv results for `query`, drops already-posted papers, translates
+/// up to `picks` of the remainder, and posts them as a threaded Slack digest in `channel`.
+/// Shared by the scheduled run and the `/paper` slash command so both go through the same
+/// dedup + translate + post path.
+pub async fn run_search(
+    backend: &(dyn TranslationBackend + Send + Sync),
+    store: &DedupStore,
+    slack: &SlackClient,
+    query: &str,
+    channel: &str,
+    max_results: i64,
+    picks: usize,
+) -> Result<(), String> {
+    let arxiv_query = arxiv::ArxivQueryBuilder::new()
+        .search_query(query)
+        .start(0)
+        .max_results(max_results)
+        .sort_by("submittedDate")
+        .sort_order("descending")
+        .build();
+    let arxivs = arxiv::fetch_arxivs(arxiv_query)
+        .await
+        .map_err(|e| format!("🛑 Failed to fetch arxivs: {}", e))?;
+
+    let mut arxivs = store.filter_unposted(arxivs).await?;
+    arxivs.shuffle(&mut rand::thread_rng());
+    let picks = min(picks, arxivs.len());
+    if picks == 0 {
+        println!("🎉 Nothing new to post for \"{}\"", query);
+        return Ok(());
+    }
+
+    let thread_ts = slack
+        .post_thread_parent(channel, &format!("📚 「{}」の検索結果 {} 件", query, picks))
+        .await?;
+
+    for arxiv in arxivs.iter().take(picks) {
+        match backend.translate(&arxiv.title, &arxiv.summary).await {
+            Ok(translated) => {
+                match slack
+                    .post_paper_reply(channel, &thread_ts, arxiv, &translated)
+                    .await
+                {
+                    Ok(_) => {
+                        println!("🎉 Successfully posted to Slack");
+                        store.mark_posted(&arxiv.id).await?;
+                    }
+                    Err(e) => println!("{}", e),
+                }
+            }
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    Ok(())
+}
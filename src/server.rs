@@ -0,0 +1,199 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use tokio::sync::mpsc;
+
+use crate::worker::{SearchRequest, SearchSender};
+
+/// Requests signed more than 5 minutes ago are rejected as a replay-attack guard.
+const MAX_TIMESTAMP_SKEW_SECS: i64 = 60 * 5;
+
+struct ServerState {
+    signing_secret: String,
+    sender: SearchSender,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlashCommandPayload {
+    text: String,
+    channel_id: String,
+}
+
+/// Runs the slim HTTP server that receives Slack's `/paper` slash command.
+pub async fn serve(addr: SocketAddr, signing_secret: String, sender: SearchSender) -> Result<(), String> {
+    let state = Arc::new(ServerState {
+        signing_secret,
+        sender,
+    });
+    let app = Router::new()
+        .route("/slack/commands", post(handle_slash_command))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| format!("🛑 Failed to bind {}: {}", addr, e))?;
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| format!("🛑 Slash command server crashed: {}", e))
+}
+
+async fn handle_slash_command(
+    State(state): State<Arc<ServerState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let signature = headers
+        .get("X-Slack-Signature")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+    let timestamp = headers
+        .get("X-Slack-Request-Timestamp")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+
+    if !verify_slack_signature(&state.signing_secret, timestamp, &body, signature) {
+        return (StatusCode::UNAUTHORIZED, "🛑 Invalid Slack signature".to_string());
+    }
+
+    let payload: SlashCommandPayload = match serde_urlencoded::from_bytes(&body) {
+        Ok(payload) => payload,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                "🛑 Malformed slash command payload".to_string(),
+            )
+        }
+    };
+
+    let query = payload.text.trim().to_string();
+    if query.is_empty() {
+        return (StatusCode::OK, "🛑 Usage: /paper <search query>".to_string());
+    }
+
+    let request = SearchRequest {
+        query: query.clone(),
+        channel_id: payload.channel_id,
+    };
+    // Slack's 3-second ack window doesn't leave room to wait for a free queue slot,
+    // so a full queue is reported back immediately rather than awaited.
+    if let Err(e) = state.sender.try_send(request) {
+        return match e {
+            mpsc::error::TrySendError::Full(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "🛑 Busy searching other requests, try again shortly".to_string(),
+            ),
+            mpsc::error::TrySendError::Closed(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "🛑 Search worker is unavailable".to_string(),
+            ),
+        };
+    }
+
+    // Slack requires an ack within 3 seconds; the actual results are posted to the
+    // channel once the worker finishes the fetch → translate → post pipeline.
+    (StatusCode::OK, format!("🔍 Searching arXiv for \"{}\"...", query))
+}
+
+fn verify_slack_signature(signing_secret: &str, timestamp: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(timestamp_secs) = timestamp.parse::<i64>() else {
+        return false;
+    };
+    if (chrono::Utc::now().timestamp() - timestamp_secs).abs() > MAX_TIMESTAMP_SKEW_SECS {
+        return false;
+    }
+
+    let base_string = [b"v0:", timestamp.as_bytes(), b":", body].concat();
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(&base_string);
+    let expected = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+
+    constant_time_eq(expected.as_bytes(), signature.as_bytes())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "shh-its-a-secret";
+
+    fn sign(secret: &str, timestamp: &str, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("v0:{}:{}", timestamp, body).as_bytes());
+        format!("v0={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn accepts_a_validly_signed_request() {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let body = "text=diffusion+models&channel_id=C123";
+        let signature = sign(SECRET, &timestamp, body);
+        assert!(verify_slack_signature(
+            SECRET,
+            &timestamp,
+            body.as_bytes(),
+            &signature
+        ));
+    }
+
+    #[test]
+    fn rejects_a_signature_made_with_the_wrong_secret() {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let body = "text=diffusion+models";
+        let signature = sign("a-different-secret", &timestamp, body);
+        assert!(!verify_slack_signature(
+            SECRET,
+            &timestamp,
+            body.as_bytes(),
+            &signature
+        ));
+    }
+
+    #[test]
+    fn rejects_a_tampered_body() {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let signature = sign(SECRET, &timestamp, "text=diffusion+models");
+        assert!(!verify_slack_signature(
+            SECRET,
+            &timestamp,
+            b"text=something+else",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn rejects_a_stale_timestamp() {
+        let timestamp = (chrono::Utc::now().timestamp() - MAX_TIMESTAMP_SKEW_SECS - 1).to_string();
+        let body = "text=diffusion+models";
+        let signature = sign(SECRET, &timestamp, body);
+        assert!(!verify_slack_signature(
+            SECRET,
+            &timestamp,
+            body.as_bytes(),
+            &signature
+        ));
+    }
+
+    #[test]
+    fn constant_time_eq_rejects_mismatched_lengths() {
+        assert!(!constant_time_eq(b"short", b"longer-value"));
+    }
+}
@@ -0,0 +1,179 @@
+use arxiv::Arxiv;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use serde::{Deserialize, Serialize};
+
+use crate::http_client::RetryingClient;
+
+const POST_MESSAGE_URL: &str = "https://slack.com/api/chat.postMessage";
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackMessage {
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+    #[serde(rename = "thread_ts", skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Header {
+        text: Text,
+    },
+    Section {
+        text: Text,
+    },
+    Actions {
+        elements: Vec<Element>,
+    },
+    Context {
+        elements: Vec<Text>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Element {
+    Button { text: Text, url: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Text {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub text: String,
+}
+
+impl Text {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self {
+            kind: "plain_text".to_string(),
+            text: text.into(),
+        }
+    }
+
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Self {
+            kind: "mrkdwn".to_string(),
+            text: text.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PostMessageResponse {
+    ok: bool,
+    ts: Option<String>,
+    error: Option<String>,
+}
+
+/// Builds the Block Kit card for a single translated paper.
+pub fn build_paper_blocks(arxiv: &Arxiv, translated: &str) -> Vec<Block> {
+    vec![
+        Block::Header {
+            text: Text::plain(arxiv.title.clone()),
+        },
+        Block::Section {
+            text: Text::mrkdwn(translated.to_string()),
+        },
+        Block::Actions {
+            elements: vec![Element::Button {
+                text: Text::plain("Read PDF"),
+                url: arxiv.pdf_url.clone(),
+            }],
+        },
+        Block::Context {
+            elements: vec![Text::mrkdwn(format!("発行日: {}", arxiv.published))],
+        },
+    ]
+}
+
+pub struct SlackClient {
+    token: String,
+    client: RetryingClient,
+}
+
+impl SlackClient {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            client: RetryingClient::from_env(),
+        }
+    }
+
+    /// Posts `text` as a thread parent message and returns its `ts` so replies can thread under it.
+    pub async fn post_thread_parent(&self, channel: &str, text: &str) -> Result<String, String> {
+        let message = SlackMessage {
+            channel: channel.to_string(),
+            text: Some(text.to_string()),
+            blocks: None,
+            thread_ts: None,
+        };
+        let response = self.send(&message).await?;
+        response
+            .ts
+            .ok_or_else(|| "🛑 Slack response did not include a ts".to_string())
+    }
+
+    /// Posts a single paper's translation as a threaded reply under `thread_ts`.
+    pub async fn post_paper_reply(
+        &self,
+        channel: &str,
+        thread_ts: &str,
+        arxiv: &Arxiv,
+        translated: &str,
+    ) -> Result<(), String> {
+        let message = SlackMessage {
+            channel: channel.to_string(),
+            text: None,
+            blocks: Some(build_paper_blocks(arxiv, translated)),
+            thread_ts: Some(thread_ts.to_string()),
+        };
+        self.send(&message).await?;
+        Ok(())
+    }
+
+    async fn send(&self, message: &SlackMessage) -> Result<PostMessageResponse, String> {
+        let bearer_auth = format!("Bearer {}", self.token);
+
+        let http_client = reqwest::Client::new();
+        let request = http_client
+            .post(POST_MESSAGE_URL)
+            .header(ACCEPT, "*/*")
+            .header(AUTHORIZATION, bearer_auth)
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&message).unwrap());
+        let response = self
+            .client
+            .send(request)
+            .await
+            .map_err(|e| format!("🛑 Request to Slack failed: {}", e))?;
+        match response.status() {
+            reqwest::StatusCode::OK => {
+                let body: PostMessageResponse = response
+                    .json()
+                    .await
+                    .map_err(|_| "🛑 Hm, the response didn't match the shape we expected.".to_string())?;
+                if body.ok {
+                    Ok(body)
+                } else {
+                    Err(format!(
+                        "🛑 Slack returned an error: {}",
+                        body.error.unwrap_or_else(|| "unknown".to_string())
+                    ))
+                }
+            }
+            reqwest::StatusCode::UNAUTHORIZED => {
+                Err("🛑 Status: UNAUTHORIZED - Need to grab a new token".to_string())
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                Err("🛑 Status: 429 - Too many requests".to_string())
+            }
+            status => Err(format!("🛑 Status: {:?} - Something unexpected happened", status)),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use tokio::sync::mpsc;
+
+use crate::db::DedupStore;
+use crate::llm::TranslationBackend;
+use crate::pipeline::run_search;
+use crate::slack::SlackClient;
+
+/// An ad-hoc search request enqueued by the `/paper` slash command.
+pub struct SearchRequest {
+    pub query: String,
+    pub channel_id: String,
+}
+
+pub type SearchSender = mpsc::Sender<SearchRequest>;
+
+/// Owns the incoming search request channel and runs the fetch → translate → post
+/// pipeline for each one, one at a time, so ad-hoc queries never race each other.
+pub async fn run(
+    mut requests: mpsc::Receiver<SearchRequest>,
+    backend: Box<dyn TranslationBackend + Send + Sync>,
+    store: DedupStore,
+    slack: SlackClient,
+    max_results: i64,
+    picks: usize,
+) {
+    while let Some(request) = requests.recv().await {
+        let result = run_search(
+            backend.as_ref(),
+            &store,
+            &slack,
+            &request.query,
+            &request.channel_id,
+            max_results,
+            picks,
+        )
+        .await;
+        if let Err(e) = result {
+            println!("{}", e);
+        }
+    }
+}